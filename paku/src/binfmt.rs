@@ -0,0 +1,61 @@
+use std::io::Read;
+
+use crate::PacError;
+
+/*
+Compact binary level format, distinct from the authoring-friendly `.lvl`
+text format:
+
+magic:       4 bytes, b"PAKL"
+version:     1 byte
+width:       u16 LE
+height:      u16 LE
+tiles:       width*height bytes, each an i8 board value
+pacman_spawn: u16 LE x, u16 LE y
+ghost_spawn:  u16 LE x, u16 LE y
+warp_count:  1 byte
+warps:       warp_count * (id: u8, x1: u16 LE, y1: u16 LE, x2: u16 LE, y2: u16 LE)
+*/
+
+pub const MAGIC: &[u8; 4] = b"PAKL";
+pub const VERSION: u8 = 1;
+
+/// Checked accessors over anything readable, so `Game::try_from_binary`
+/// doesn't have to hand-roll bounds checking for every field it pulls out.
+/// Layered on `Read::read_exact`, so a short read always becomes
+/// `PacError::TruncatedData` instead of a panic or garbage value.
+pub trait ByteReaderExt: Read {
+    fn read_u8_checked(&mut self) -> Result<u8, PacError> {
+        let mut buf = [0u8; 1];
+        self.read_exact(&mut buf)
+            .map_err(|_| PacError::TruncatedData)?;
+        Ok(buf[0])
+    }
+
+    fn read_i8_checked(&mut self) -> Result<i8, PacError> {
+        Ok(self.read_u8_checked()? as i8)
+    }
+
+    fn read_u16_le(&mut self) -> Result<u16, PacError> {
+        let mut buf = [0u8; 2];
+        self.read_exact(&mut buf)
+            .map_err(|_| PacError::TruncatedData)?;
+        Ok(u16::from_le_bytes(buf))
+    }
+
+    fn read_i32_le(&mut self) -> Result<i32, PacError> {
+        let mut buf = [0u8; 4];
+        self.read_exact(&mut buf)
+            .map_err(|_| PacError::TruncatedData)?;
+        Ok(i32::from_le_bytes(buf))
+    }
+
+    /// A `(x, y)` tile coordinate, stored as two little-endian u16s.
+    fn read_pair(&mut self) -> Result<(usize, usize), PacError> {
+        let x = self.read_u16_le()? as usize;
+        let y = self.read_u16_le()? as usize;
+        Ok((x, y))
+    }
+}
+
+impl<R: Read + ?Sized> ByteReaderExt for R {}
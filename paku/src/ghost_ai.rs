@@ -0,0 +1,357 @@
+use ndarray::Array2;
+
+/*
+Target-tile ghost AI, modeled on the original arcade's four distinct
+personalities: https://pacman.holenet.info/
+
+Each ghost only picks a new direction when centered on a tile (an
+"intersection"). Outside of that it just keeps moving the way it was
+already going.
+*/
+
+/// Compass direction an entity is currently moving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+impl Direction {
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Left => (-1, 0),
+            Direction::Down => (0, 1),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Left => Direction::Right,
+            Direction::Down => Direction::Up,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+/// The four ghosts, each with its own targeting behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ghost {
+    Blinky,
+    Pinky,
+    Inky,
+    Clyde,
+}
+
+/// Global scatter/chase phase, shared by every non-frightened ghost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobalMode {
+    Scatter,
+    Chase,
+}
+
+/// Per-ghost behavior mode. Frightened overrides the global mode individually
+/// (entered on power-pellet, cleared once the fright timer runs out).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GhostMode {
+    Scatter,
+    Chase,
+    Frightened,
+}
+
+/// How fast a ghost covers ground, in tiles per second.
+pub const GHOST_SPEED_TILES_PER_SEC: f64 = 4.0;
+
+/// Entities move on a grid 4x finer than the board's own tiles (a 28x31
+/// board thus gives entities a 112x124 fine grid), matching the arcade
+/// original's sub-tile movement precision. One board tile spans
+/// `FINE_SCALE` fine units; the center of board tile `(x, y)` sits at fine
+/// position `(x*FINE_SCALE + FINE_SCALE/2, y*FINE_SCALE + FINE_SCALE/2)`,
+/// which is the only place an entity is allowed to turn.
+pub const FINE_SCALE: f64 = 4.0;
+
+/// The fine-grid position of the center of board tile `tile`.
+pub fn tile_center(tile: usize) -> f64 {
+    tile as f64 * FINE_SCALE + FINE_SCALE / 2.0
+}
+
+/// The board tile a fine-grid coordinate currently sits within.
+pub fn fine_to_tile(fine: f64) -> usize {
+    (fine / FINE_SCALE).floor().max(0.0) as usize
+}
+
+/// Durations (seconds) of the alternating scatter/chase phases, in order.
+/// The last entry holds chase indefinitely, matching the arcade original.
+pub const SCATTER_CHASE_SCHEDULE: &[(GlobalMode, f64)] = &[
+    (GlobalMode::Scatter, 7.0),
+    (GlobalMode::Chase, 20.0),
+    (GlobalMode::Scatter, 7.0),
+    (GlobalMode::Chase, 20.0),
+    (GlobalMode::Scatter, 5.0),
+    (GlobalMode::Chase, 20.0),
+    (GlobalMode::Scatter, 5.0),
+    (GlobalMode::Chase, f64::INFINITY),
+];
+
+/// Looks up which global phase is active `elapsed` seconds into the level.
+pub fn global_mode_at(elapsed: f64) -> GlobalMode {
+    let mut acc = 0.0;
+    for &(mode, dur) in SCATTER_CHASE_SCHEDULE {
+        acc += dur;
+        if elapsed < acc {
+            return mode;
+        }
+    }
+    GlobalMode::Chase
+}
+
+/// Fixed scatter-mode home corner for each ghost, in tile coordinates.
+pub fn scatter_corner(ghost: Ghost, width: usize, height: usize) -> (usize, usize) {
+    match ghost {
+        Ghost::Blinky => (width - 1, 0),
+        Ghost::Pinky => (0, 0),
+        Ghost::Inky => (width - 1, height - 1),
+        Ghost::Clyde => (0, height - 1),
+    }
+}
+
+/// The single tile adjacent to `tile` in direction `dir`, clamped to the
+/// board edges. Shared with Pac-Man's own movement in `pacman`.
+pub fn tile_ahead(
+    tile: (usize, usize),
+    dir: Direction,
+    width: usize,
+    height: usize,
+) -> (usize, usize) {
+    ahead_of(tile, dir, 1, width, height)
+}
+
+fn ahead_of(
+    tile: (usize, usize),
+    dir: Direction,
+    n: isize,
+    width: usize,
+    height: usize,
+) -> (usize, usize) {
+    let (dx, dy) = dir.delta();
+    let x = (tile.0 as isize + dx * n).clamp(0, width as isize - 1) as usize;
+    let y = (tile.1 as isize + dy * n).clamp(0, height as isize - 1) as usize;
+    (x, y)
+}
+
+fn tile_dist_sq(a: (usize, usize), b: (usize, usize)) -> f64 {
+    let dx = a.0 as f64 - b.0 as f64;
+    let dy = a.1 as f64 - b.1 as f64;
+    dx * dx + dy * dy
+}
+
+/// Computes the tile `ghost` is currently pathing toward, per the authentic
+/// target-tile model. `blinky_tile` is only consulted for Inky's calculation,
+/// and `Frightened` has no meaningful target (the caller should not ask).
+#[allow(clippy::too_many_arguments)]
+pub fn target_tile(
+    ghost: Ghost,
+    mode: GhostMode,
+    ghost_tile: (usize, usize),
+    pacman_tile: (usize, usize),
+    pacman_dir: Direction,
+    blinky_tile: (usize, usize),
+    width: usize,
+    height: usize,
+) -> (usize, usize) {
+    if mode == GhostMode::Scatter {
+        return scatter_corner(ghost, width, height);
+    }
+
+    match ghost {
+        Ghost::Blinky => pacman_tile,
+        Ghost::Pinky => ahead_of(pacman_tile, pacman_dir, 4, width, height),
+        Ghost::Inky => {
+            let pivot = ahead_of(pacman_tile, pacman_dir, 2, width, height);
+            let vx = pivot.0 as isize - blinky_tile.0 as isize;
+            let vy = pivot.1 as isize - blinky_tile.1 as isize;
+            let x = (pivot.0 as isize + vx).clamp(0, width as isize - 1) as usize;
+            let y = (pivot.1 as isize + vy).clamp(0, height as isize - 1) as usize;
+            (x, y)
+        }
+        Ghost::Clyde => {
+            if tile_dist_sq(ghost_tile, pacman_tile).sqrt() > 8.0 {
+                pacman_tile
+            } else {
+                scatter_corner(ghost, width, height)
+            }
+        }
+    }
+}
+
+/// Candidate directions in the fixed tie-break order used at every intersection.
+const DIRECTION_PRIORITY: [Direction; 4] = [
+    Direction::Up,
+    Direction::Left,
+    Direction::Down,
+    Direction::Right,
+];
+
+/// Picks the direction a ghost should leave an intersection by, given its
+/// current facing (to forbid the 180-degree reversal) and its target tile.
+/// In `Frightened` mode the target is ignored and a valid direction is chosen
+/// pseudo-randomly instead.
+pub fn choose_direction(
+    board: &Array2<i32>,
+    tile: (usize, usize),
+    facing: Direction,
+    mode: GhostMode,
+    target: (usize, usize),
+    rng_state: &mut u64,
+) -> Direction {
+    let (height, width) = board.dim();
+    let forbidden = facing.opposite();
+
+    let candidates: Vec<Direction> = DIRECTION_PRIORITY
+        .iter()
+        .copied()
+        .filter(|&dir| dir != forbidden)
+        .filter(|&dir| {
+            let (x, y) = tile_ahead(tile, dir, width, height);
+            board[[y, x]] != 1
+        })
+        .collect();
+
+    if candidates.is_empty() {
+        // dead end: reversing is the only legal move
+        return forbidden;
+    }
+
+    if mode == GhostMode::Frightened {
+        let pick = next_rand(rng_state) as usize % candidates.len();
+        return candidates[pick];
+    }
+
+    *candidates
+        .iter()
+        .min_by(|&&a, &&b| {
+            let da = tile_dist_sq(tile_ahead(tile, a, width, height), target);
+            let db = tile_dist_sq(tile_ahead(tile, b, width, height), target);
+            da.partial_cmp(&db).unwrap()
+        })
+        .unwrap()
+}
+
+/// Is `loc` sitting exactly on a tile center (the only place a ghost is
+/// allowed to turn)? Spawns and tile centers both land on a fine-grid
+/// position of `tile*FINE_SCALE + FINE_SCALE/2`.
+pub fn is_at_tile_center(loc: (f64, f64)) -> bool {
+    const EPS: f64 = 1e-6;
+    let on_center = |v: f64| {
+        let offset = (v - FINE_SCALE / 2.0) / FINE_SCALE;
+        (offset - offset.round()).abs() < EPS
+    };
+    on_center(loc.0) && on_center(loc.1)
+}
+
+/// The tile an entity at `loc` is heading toward along `dir`. Between
+/// centers a plain `floor(loc / FINE_SCALE)` would already report the tile
+/// the entity's leading edge has poked into, one tile too far ahead of
+/// where it's actually aiming; this instead uses the tile whose center was
+/// most recently occupied (or is currently occupied) and steps one further
+/// in `dir`, so it agrees with `advance`'s own notion of "ahead" even
+/// for a `loc` that's mid-tile.
+pub fn tile_ahead_of_loc(
+    loc: (f64, f64),
+    dir: Direction,
+    width: usize,
+    height: usize,
+) -> (usize, usize) {
+    let (dx, dy) = dir.delta();
+    let last_center_tile = |pos: f64, delta: isize| -> usize {
+        let v = (pos - FINE_SCALE / 2.0) / FINE_SCALE;
+        if delta < 0 {
+            v.ceil().max(0.0) as usize
+        } else {
+            v.floor().max(0.0) as usize
+        }
+    };
+    let tile = (last_center_tile(loc.0, dx), last_center_tile(loc.1, dy));
+    tile_ahead(tile, dir, width, height)
+}
+
+/// Advances `loc` by `speed` tiles/sec for `dt` seconds, clamped so it
+/// never overshoots the center of the tile immediately ahead. Without this,
+/// a per-tick move almost never lands exactly on a center, so
+/// `is_at_tile_center` would never fire and entities would glide straight
+/// through every intersection (and every wall, since `choose_direction` is
+/// only consulted at centers). The caller is responsible for checking that
+/// `dir` isn't blocked by a wall before calling this.
+pub fn advance(loc: &mut (f64, f64), dir: Direction, speed: f64, dt: f64, width: usize, height: usize) {
+    let ahead = tile_ahead_of_loc(*loc, dir, width, height);
+    let target = (tile_center(ahead.0), tile_center(ahead.1));
+    let (dx, dy) = dir.delta();
+    let fine_speed = speed * FINE_SCALE;
+
+    let step_axis = |pos: f64, delta: f64, goal: f64| -> f64 {
+        let moved = pos + delta;
+        if delta > 0.0 {
+            moved.min(goal)
+        } else if delta < 0.0 {
+            moved.max(goal)
+        } else {
+            pos
+        }
+    };
+
+    loc.0 = step_axis(loc.0, dx as f64 * fine_speed * dt, target.0);
+    loc.1 = step_axis(loc.1, dy as f64 * fine_speed * dt, target.1);
+}
+
+/// Advances one ghost by `dt` seconds: re-targets at intersections, then
+/// moves it along its current direction at `GHOST_SPEED_TILES_PER_SEC`.
+#[allow(clippy::too_many_arguments)]
+pub fn step_ghost(
+    loc: &mut (f64, f64),
+    dir: &mut Direction,
+    mode: GhostMode,
+    ghost: Ghost,
+    pacman_tile: (usize, usize),
+    pacman_dir: Direction,
+    blinky_tile: (usize, usize),
+    board: &Array2<i32>,
+    dt: f64,
+    rng_state: &mut u64,
+) {
+    let (height, width) = board.dim();
+    let tile = (fine_to_tile(loc.0), fine_to_tile(loc.1));
+
+    if is_at_tile_center(*loc) {
+        let target = target_tile(
+            ghost,
+            mode,
+            tile,
+            pacman_tile,
+            pacman_dir,
+            blinky_tile,
+            width,
+            height,
+        );
+        *dir = choose_direction(board, tile, *dir, mode, target, rng_state);
+    }
+
+    let ahead = tile_ahead_of_loc(*loc, *dir, width, height);
+    if board[[ahead.1, ahead.0]] != 1 {
+        advance(loc, *dir, GHOST_SPEED_TILES_PER_SEC, dt, width, height);
+    }
+}
+
+/// A small xorshift64 PRNG so frightened-mode turns don't need to pull in an
+/// external RNG crate for something this inconsequential.
+fn next_rand(state: &mut u64) -> u64 {
+    let mut x = *state;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    *state = x;
+    x
+}
@@ -1,4 +1,8 @@
+mod binfmt;
+mod ghost_ai;
 mod pacman;
+mod reachability;
+mod renderer;
 mod tyrosine_wrap;
 
 use std::path::Path;
@@ -46,6 +50,21 @@ pub enum PacError {
     InvalidCharacters,
     #[error("Yeah idk what causes this error yet, but it happens when converting to a 2D NDArray.")]
     ConversionToArray,
+
+    #[error("Binary level file doesn't start with the expected magic header.")]
+    BadMagic,
+    #[error("Binary level file uses a format version this build doesn't support.")]
+    UnsupportedVersion,
+    #[error("Binary level file is truncated or corrupt.")]
+    TruncatedData,
+
+    #[error("Tile at ({x}, {y}) can't be reached from the Pac-Man spawn.")]
+    UnreachableTile { x: usize, y: usize },
+
+    #[error("Spawn point at ({x}, {y}) is out of bounds for this board.")]
+    SpawnOutOfBounds { x: usize, y: usize },
+    #[error("Warp endpoint at ({x}, {y}) is out of bounds for this board.")]
+    WarpOutOfBounds { x: usize, y: usize },
 }
 
 fn main() {
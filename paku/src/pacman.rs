@@ -1,8 +1,12 @@
 use ndarray::Array2;
 use std::collections::HashMap;
 use std::fs;
+use std::io::Read;
 use std::path::Path;
 
+use crate::binfmt::{self, ByteReaderExt};
+use crate::ghost_ai::{self, Direction, Ghost, GhostMode};
+use crate::reachability;
 use crate::PacError;
 
 /*
@@ -19,10 +23,51 @@ Possibly spawn ghosts in this way:
 as this will space the ghosts apart by 1 tile, so no overlap
 */
 
+/// How fast Pac-Man covers ground, in tiles per second.
+pub const PACMAN_SPEED_TILES_PER_SEC: f64 = 4.0;
+
+/// How long a power pellet keeps the ghosts frightened, in seconds.
+pub const FRIGHTENED_DURATION_SECS: f64 = 6.0;
+
+/// How long an uneaten fruit stays on the board before it disappears.
+pub const FRUIT_LIFETIME_SECS: f64 = 9.5;
+
+/// Score for eating the 1st/2nd/3rd/4th ghost of a single frightened streak.
+pub const GHOST_EATEN_POINTS: [usize; 4] = [200, 400, 800, 1600];
+
+fn fruit_value_for_level(level: usize) -> usize {
+    match level {
+        1 => 100,
+        2 => 300,
+        3 | 4 => 500,
+        5 | 6 => 700,
+        7 | 8 => 1000,
+        9 | 10 => 2000,
+        11 | 12 => 3000,
+        _ => 5000,
+    }
+}
+
+/// A warp id (the negated digit from the level source) mapped to the two
+/// tile coordinates it pairs together.
+pub type WarpTable = HashMap<i32, ((usize, usize), (usize, usize))>;
+
+/// Something noteworthy that happened during a `Game::step`, for a front-end
+/// to react to (sound effects, animations, etc).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameEvent {
+    DotEaten,
+    GhostEaten,
+    PacDied,
+    LevelCleared,
+}
+
 pub struct Game {
     /*
     note: these are 4x as fine as the tiles of the map
     - the map is 28x31, so entities have a map of 112x124
+    - see `ghost_ai::FINE_SCALE`/`tile_center`; the only place an entity is
+      allowed to turn is a tile center (see `ghost_ai::is_at_tile_center`)
     */
     pub pacman_loc: (f64, f64),
     pub blinky_loc: (f64, f64),
@@ -30,6 +75,35 @@ pub struct Game {
     pub inky_loc: (f64, f64),
     pub clyde_loc: (f64, f64),
 
+    /// the way Pac-Man is currently facing; ghosts need this to compute
+    /// their chase targets
+    pub pacman_dir: Direction,
+
+    pub blinky_dir: Direction,
+    pub pinky_dir: Direction,
+    pub inky_dir: Direction,
+    pub clyde_dir: Direction,
+
+    pub blinky_mode: GhostMode,
+    pub pinky_mode: GhostMode,
+    pub inky_mode: GhostMode,
+    pub clyde_mode: GhostMode,
+
+    /// set right after teleporting through a warp, cleared once the entity
+    /// has actually left the warp cell; prevents bouncing straight back
+    pub pacman_just_warped: bool,
+    pub blinky_just_warped: bool,
+    pub pinky_just_warped: bool,
+    pub inky_just_warped: bool,
+    pub clyde_just_warped: bool,
+
+    /// seconds since the level started, used to look up the scatter/chase
+    /// phase in `ghost_ai::SCATTER_CHASE_SCHEDULE`
+    pub mode_elapsed: f64,
+
+    /// xorshift64 state driving frightened-mode turns
+    pub rng_state: u64,
+
     /// left of the 2x1
     pub pacman_spawn: (usize, usize),
 
@@ -40,7 +114,31 @@ pub struct Game {
     pub lives: usize,
 
     /// exist in perfect pairs, we store their coordinates
-    pub warps: HashMap<i32, ((usize, usize), (usize, usize))>,
+    pub warps: WarpTable,
+
+    /// current level, used to scale the fruit's point value
+    pub level: usize,
+
+    /// total number of pac-dots and power pellets the level started with
+    pub dots_total: usize,
+    /// how many of those have been eaten so far
+    pub dots_eaten: usize,
+    /// whether `GameEvent::LevelCleared` has already fired, so it's only
+    /// reported once at the moment the last dot is eaten
+    pub level_cleared: bool,
+
+    /// seconds left of the current frightened period; 0 when not active
+    pub frightened_timer: f64,
+    /// how many ghosts have been eaten during the current frightened period
+    pub ghost_eat_streak: usize,
+
+    pub fruit_spawned_70: bool,
+    pub fruit_spawned_170: bool,
+    pub fruit_active: bool,
+    pub fruit_timer: f64,
+    pub fruit_value: usize,
+    /// tile the fruit sits on while active
+    pub fruit_loc: (usize, usize),
 
     /*
     breakdown:
@@ -233,7 +331,7 @@ impl Game {
         let warps = warp_coords
             .iter()
             .map(|(id, coords)| (*id as i32 * -1, (coords[0], coords[1])))
-            .collect::<HashMap<i32, ((usize, usize), (usize, usize))>>();
+            .collect::<WarpTable>();
 
         // Build the numeric board
         // row-major: iterate y then x
@@ -254,8 +352,19 @@ impl Game {
                         0
                     }
                     '@' => {
-                        // similar to $, we convert @ to a wall
-                        1
+                        // the block is mostly wall, but the ghosts parked on
+                        // its middle row need to be able to walk to the
+                        // center column and out through the exit tile above
+                        // the block (see `validate_reachability`'s
+                        // `ghost_exit`), or they can never leave the house
+                        let (hx, hy) = ghost_spawn;
+                        let on_holding_row = y == hy + 2;
+                        let on_exit_column = x == hx + 3;
+                        if on_holding_row || on_exit_column {
+                            0
+                        } else {
+                            1
+                        }
                     }
                     '1'..='9' => {
                         let id = c.to_digit(10).unwrap() as i32;
@@ -273,22 +382,569 @@ impl Game {
         let board = Array2::from_shape_vec((height, width), flat)
             .map_err(|_| PacError::ConversionToArray)?;
 
-        // spawns: place all ghosts at ghost_spawn and pacman at pacman_spawn
+        Game::validate_reachability(&board, pacman_spawn, ghost_spawn, &warps)?;
+
+        Ok(Game::fresh(board, pacman_spawn, ghost_spawn, warps))
+    }
+
+    /// Parses the compact binary level format (see `binfmt`): a validated,
+    /// round-trippable alternative to the text `.lvl` format that skips the
+    /// repeated grid scans `try_from_file` does.
+    pub fn try_from_binary(mut bytes: &[u8]) -> Result<Self, PacError> {
+        let mut magic = [0u8; 4];
+        bytes
+            .read_exact(&mut magic)
+            .map_err(|_| PacError::TruncatedData)?;
+        if &magic != binfmt::MAGIC {
+            return Err(PacError::BadMagic);
+        }
+
+        let version = bytes.read_u8_checked()?;
+        if version != binfmt::VERSION {
+            return Err(PacError::UnsupportedVersion);
+        }
+
+        let width = bytes.read_u16_le()? as usize;
+        let height = bytes.read_u16_le()? as usize;
+
+        let mut flat: Vec<i32> = Vec::with_capacity(width * height);
+        for _ in 0..width * height {
+            flat.push(bytes.read_i8_checked()? as i32);
+        }
+        let board = Array2::from_shape_vec((height, width), flat)
+            .map_err(|_| PacError::ConversionToArray)?;
+
+        let pacman_spawn = bytes.read_pair()?;
+        let ghost_spawn = bytes.read_pair()?;
+        Game::validate_spawns_in_bounds(&board, pacman_spawn, ghost_spawn)?;
+
+        let warp_count = bytes.read_u8_checked()?;
+        let mut warps = HashMap::with_capacity(warp_count as usize);
+        for _ in 0..warp_count {
+            let id = bytes.read_u8_checked()? as i32;
+            let a = bytes.read_pair()?;
+            let b = bytes.read_pair()?;
+            warps.insert(-id, (a, b));
+        }
+        Game::validate_warps_in_bounds(&board, &warps)?;
+
+        Game::validate_reachability(&board, pacman_spawn, ghost_spawn, &warps)?;
+
+        Ok(Game::fresh(board, pacman_spawn, ghost_spawn, warps))
+    }
+
+    /// Bounds-checks spawns parsed from untrusted binary data before they're
+    /// used to index the board. The text format gets the same guarantee for
+    /// free from its own parsing checks (a stray `$`/`@` is rejected before
+    /// a spawn is ever returned), but `try_from_binary` trusts raw bytes.
+    fn validate_spawns_in_bounds(
+        board: &Array2<i32>,
+        pacman_spawn: (usize, usize),
+        ghost_spawn: (usize, usize),
+    ) -> Result<(), PacError> {
+        let (height, width) = board.dim();
+
+        // pacman spawn is the left half of a horizontal 2x1
+        if pacman_spawn.0 + 1 >= width || pacman_spawn.1 >= height {
+            return Err(PacError::SpawnOutOfBounds {
+                x: pacman_spawn.0,
+                y: pacman_spawn.1,
+            });
+        }
+
+        // ghost spawn is the top-left of an 8x5 block, with a blank row
+        // required above and below its center for the ghost-house exit
+        if ghost_spawn.1 == 0
+            || ghost_spawn.0 + 8 > width
+            || ghost_spawn.1 + 5 >= height
+        {
+            return Err(PacError::SpawnOutOfBounds {
+                x: ghost_spawn.0,
+                y: ghost_spawn.1,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Bounds-checks every warp endpoint parsed from untrusted binary data.
+    /// Like the spawns, the text format gets this for free (a warp digit can
+    /// only ever point at a cell the parser actually visited), but
+    /// `try_from_binary` trusts raw coordinate bytes that `reachable_tiles`
+    /// would otherwise index straight into.
+    fn validate_warps_in_bounds(board: &Array2<i32>, warps: &WarpTable) -> Result<(), PacError> {
+        let (height, width) = board.dim();
+        for &(a, b) in warps.values() {
+            for (x, y) in [a, b] {
+                if x >= width || y >= height {
+                    return Err(PacError::WarpOutOfBounds { x, y });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Flood-fills from the Pac-Man spawn and confirms every dot/pellet and
+    /// the ghost-house exit tile are actually reachable. Shared by
+    /// `try_from_file` and `try_from_binary` so the two loaders can't drift
+    /// apart on this guarantee.
+    fn validate_reachability(
+        board: &Array2<i32>,
+        pacman_spawn: (usize, usize),
+        ghost_spawn: (usize, usize),
+        warps: &WarpTable,
+    ) -> Result<(), PacError> {
+        let (height, width) = board.dim();
+        let reachable = reachability::reachable_tiles(board, pacman_spawn, warps);
+        for y in 0..height {
+            for x in 0..width {
+                let v = board[[y, x]];
+                if (v == 2 || v == 3) && !reachable.contains(&(x, y)) {
+                    return Err(PacError::UnreachableTile { x, y });
+                }
+            }
+        }
+        let exit_y = ghost_spawn.1.checked_sub(1).ok_or(PacError::SpawnOutOfBounds {
+            x: ghost_spawn.0,
+            y: ghost_spawn.1,
+        })?;
+        let ghost_exit = (ghost_spawn.0 + 3, exit_y);
+        if !reachable.contains(&ghost_exit) {
+            return Err(PacError::UnreachableTile {
+                x: ghost_exit.0,
+                y: ghost_exit.1,
+            });
+        }
+        Ok(())
+    }
+
+    /// Serializes this level's static layout (board, spawns, warps) to the
+    /// compact binary format; round-trips through `try_from_binary`.
+    pub fn to_binary(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(binfmt::MAGIC);
+        out.push(binfmt::VERSION);
+
+        let (height, width) = self.board.dim();
+        out.extend_from_slice(&(width as u16).to_le_bytes());
+        out.extend_from_slice(&(height as u16).to_le_bytes());
+        for &v in self.board.iter() {
+            out.push(v as i8 as u8);
+        }
+
+        out.extend_from_slice(&(self.pacman_spawn.0 as u16).to_le_bytes());
+        out.extend_from_slice(&(self.pacman_spawn.1 as u16).to_le_bytes());
+        out.extend_from_slice(&(self.ghost_spawn.0 as u16).to_le_bytes());
+        out.extend_from_slice(&(self.ghost_spawn.1 as u16).to_le_bytes());
+
+        out.push(self.warps.len() as u8);
+        for (&id, &(a, b)) in self.warps.iter() {
+            out.push((-id) as u8);
+            out.extend_from_slice(&(a.0 as u16).to_le_bytes());
+            out.extend_from_slice(&(a.1 as u16).to_le_bytes());
+            out.extend_from_slice(&(b.0 as u16).to_le_bytes());
+            out.extend_from_slice(&(b.1 as u16).to_le_bytes());
+        }
+
+        out
+    }
+
+    /// Builds a `Game` at its starting state from an already-parsed board,
+    /// spawns, and warp table. Shared by `try_from_file` and `try_from_binary`
+    /// so spawn placement can't drift between the two formats.
+    fn fresh(
+        board: Array2<i32>,
+        pacman_spawn: (usize, usize),
+        ghost_spawn: (usize, usize),
+        warps: WarpTable,
+    ) -> Self {
+        let dots_total = board.iter().filter(|&&v| v == 2 || v == 3).count();
         let (px, py) = pacman_spawn;
         let (gx, gy) = ghost_spawn;
 
-        Ok(Game {
+        Game {
             pacman_spawn,
             ghost_spawn,
-            pacman_loc: (px as f64 + 0.5, py as f64), //center pacman properly in his spawn
-            blinky_loc: (gx as f64 + 3.5, gy as f64 - 1.0), //place blinky above the spawn
-            pinky_loc: (gx as f64 + 3.5, gy as f64 + 2.0), //place pinky at the center of spawn
-            inky_loc: (gx as f64 + 1.5, gy as f64 + 2.0), //place inky on the left of pinky
-            clyde_loc: (gx as f64 + 5.5, gy as f64 + 2.0), //place clyde on the right of pinky
+            pacman_loc: (ghost_ai::tile_center(px), ghost_ai::tile_center(py)), //center pacman on the left tile of his 2x1 spawn
+            blinky_loc: (ghost_ai::tile_center(gx + 3), ghost_ai::tile_center(gy - 1)), //place blinky above the spawn
+            pinky_loc: (ghost_ai::tile_center(gx + 3), ghost_ai::tile_center(gy + 2)), //place pinky at the center of spawn
+            inky_loc: (ghost_ai::tile_center(gx + 1), ghost_ai::tile_center(gy + 2)), //place inky on the left of pinky
+            clyde_loc: (ghost_ai::tile_center(gx + 5), ghost_ai::tile_center(gy + 2)), //place clyde on the right of pinky
+            pacman_dir: Direction::Left,
+            blinky_dir: Direction::Up,
+            pinky_dir: Direction::Up,
+            inky_dir: Direction::Up,
+            clyde_dir: Direction::Up,
+            blinky_mode: GhostMode::Scatter,
+            pinky_mode: GhostMode::Scatter,
+            inky_mode: GhostMode::Scatter,
+            clyde_mode: GhostMode::Scatter,
+            pacman_just_warped: false,
+            blinky_just_warped: false,
+            pinky_just_warped: false,
+            inky_just_warped: false,
+            clyde_just_warped: false,
+            mode_elapsed: 0.0,
+            rng_state: 0x9E3779B97F4A7C15,
             board,
             lives: 3,
             points: 0,
             warps,
-        })
+            level: 1,
+            dots_total,
+            dots_eaten: 0,
+            level_cleared: false,
+            frightened_timer: 0.0,
+            ghost_eat_streak: 0,
+            fruit_spawned_70: false,
+            fruit_spawned_170: false,
+            fruit_active: false,
+            fruit_timer: 0.0,
+            fruit_value: 0,
+            fruit_loc: (0, 0),
+        }
+    }
+
+    /// Advances every ghost by `dt` seconds, per the scatter/chase/frightened
+    /// target-tile model in `ghost_ai`. Pac-Man's own movement is handled
+    /// elsewhere; this only drives the four ghosts.
+    pub fn step_ghosts(&mut self, dt: f64) {
+        self.mode_elapsed += dt;
+        let global_mode = ghost_ai::global_mode_at(self.mode_elapsed);
+
+        let pacman_tile = (
+            ghost_ai::fine_to_tile(self.pacman_loc.0),
+            ghost_ai::fine_to_tile(self.pacman_loc.1),
+        );
+        let blinky_tile = (
+            ghost_ai::fine_to_tile(self.blinky_loc.0),
+            ghost_ai::fine_to_tile(self.blinky_loc.1),
+        );
+
+        for gmode in [
+            &mut self.blinky_mode,
+            &mut self.pinky_mode,
+            &mut self.inky_mode,
+            &mut self.clyde_mode,
+        ] {
+            // Frightened is only cleared once the fright timer actually runs
+            // out; otherwise it sticks until a ghost is eaten or resets.
+            if *gmode != GhostMode::Frightened || self.frightened_timer <= 0.0 {
+                *gmode = match global_mode {
+                    ghost_ai::GlobalMode::Scatter => GhostMode::Scatter,
+                    ghost_ai::GlobalMode::Chase => GhostMode::Chase,
+                };
+            }
+        }
+
+        ghost_ai::step_ghost(
+            &mut self.blinky_loc,
+            &mut self.blinky_dir,
+            self.blinky_mode,
+            Ghost::Blinky,
+            pacman_tile,
+            self.pacman_dir,
+            blinky_tile,
+            &self.board,
+            dt,
+            &mut self.rng_state,
+        );
+        ghost_ai::step_ghost(
+            &mut self.pinky_loc,
+            &mut self.pinky_dir,
+            self.pinky_mode,
+            Ghost::Pinky,
+            pacman_tile,
+            self.pacman_dir,
+            blinky_tile,
+            &self.board,
+            dt,
+            &mut self.rng_state,
+        );
+        ghost_ai::step_ghost(
+            &mut self.inky_loc,
+            &mut self.inky_dir,
+            self.inky_mode,
+            Ghost::Inky,
+            pacman_tile,
+            self.pacman_dir,
+            blinky_tile,
+            &self.board,
+            dt,
+            &mut self.rng_state,
+        );
+        ghost_ai::step_ghost(
+            &mut self.clyde_loc,
+            &mut self.clyde_dir,
+            self.clyde_mode,
+            Ghost::Clyde,
+            pacman_tile,
+            self.pacman_dir,
+            blinky_tile,
+            &self.board,
+            dt,
+            &mut self.rng_state,
+        );
+
+        for ghost in [Ghost::Blinky, Ghost::Pinky, Ghost::Inky, Ghost::Clyde] {
+            self.step_ghost_warp(ghost);
+        }
+    }
+
+    fn ghost_state(
+        &mut self,
+        ghost: Ghost,
+    ) -> (&mut (f64, f64), &mut Direction, &mut GhostMode, &mut bool) {
+        match ghost {
+            Ghost::Blinky => (
+                &mut self.blinky_loc,
+                &mut self.blinky_dir,
+                &mut self.blinky_mode,
+                &mut self.blinky_just_warped,
+            ),
+            Ghost::Pinky => (
+                &mut self.pinky_loc,
+                &mut self.pinky_dir,
+                &mut self.pinky_mode,
+                &mut self.pinky_just_warped,
+            ),
+            Ghost::Inky => (
+                &mut self.inky_loc,
+                &mut self.inky_dir,
+                &mut self.inky_mode,
+                &mut self.inky_just_warped,
+            ),
+            Ghost::Clyde => (
+                &mut self.clyde_loc,
+                &mut self.clyde_dir,
+                &mut self.clyde_mode,
+                &mut self.clyde_just_warped,
+            ),
+        }
+    }
+
+    /// Looks up the warp partner tile for `tile`, validating that the
+    /// destination is in bounds and walkable before handing it back. Guards
+    /// the classic off-by-one/negative-subscript pitfall of trusting an
+    /// unvalidated warp id straight into the board array.
+    fn warp_destination(&self, tile: (usize, usize)) -> Option<(usize, usize)> {
+        let id = self.board[[tile.1, tile.0]];
+        if id >= 0 {
+            return None;
+        }
+        let (a, b) = self.warps.get(&id)?;
+        let dest = if *a == tile { *b } else { *a };
+
+        let (height, width) = self.board.dim();
+        if dest.0 >= width || dest.1 >= height {
+            return None;
+        }
+        if self.board[[dest.1, dest.0]] == 1 {
+            return None;
+        }
+        Some(dest)
+    }
+
+    fn is_warp_cell(&self, tile: (usize, usize)) -> bool {
+        self.board[[tile.1, tile.0]] < 0
+    }
+
+    fn ghost_home_loc(&self, ghost: Ghost) -> (f64, f64) {
+        let (gx, gy) = self.ghost_spawn;
+        match ghost {
+            Ghost::Blinky => (ghost_ai::tile_center(gx + 3), ghost_ai::tile_center(gy - 1)),
+            Ghost::Pinky => (ghost_ai::tile_center(gx + 3), ghost_ai::tile_center(gy + 2)),
+            Ghost::Inky => (ghost_ai::tile_center(gx + 1), ghost_ai::tile_center(gy + 2)),
+            Ghost::Clyde => (ghost_ai::tile_center(gx + 5), ghost_ai::tile_center(gy + 2)),
+        }
+    }
+
+    /// Sends Pac-Man and every ghost back to their spawns, e.g. after a death.
+    fn reset_positions(&mut self) {
+        let (px, py) = self.pacman_spawn;
+        self.pacman_loc = (ghost_ai::tile_center(px), ghost_ai::tile_center(py));
+        self.pacman_dir = Direction::Left;
+
+        for ghost in [Ghost::Blinky, Ghost::Pinky, Ghost::Inky, Ghost::Clyde] {
+            let home = self.ghost_home_loc(ghost);
+            let (loc, dir, mode, just_warped) = self.ghost_state(ghost);
+            *loc = home;
+            *dir = Direction::Up;
+            *mode = GhostMode::Scatter;
+            *just_warped = false;
+        }
+
+        self.pacman_just_warped = false;
+        self.frightened_timer = 0.0;
+        self.ghost_eat_streak = 0;
+    }
+
+    /// Moves Pac-Man toward `desired_dir`, only turning at tile centers and
+    /// only when the target tile is non-wall; otherwise keeps going straight,
+    /// stopping dead if a wall is directly ahead. Then resolves a warp if
+    /// Pac-Man has landed on one.
+    fn step_pacman(&mut self, desired_dir: Direction, dt: f64) {
+        let (height, width) = self.board.dim();
+
+        if ghost_ai::is_at_tile_center(self.pacman_loc) {
+            let ahead = ghost_ai::tile_ahead_of_loc(self.pacman_loc, desired_dir, width, height);
+            if self.board[[ahead.1, ahead.0]] != 1 {
+                self.pacman_dir = desired_dir;
+            }
+        }
+
+        let ahead = ghost_ai::tile_ahead_of_loc(self.pacman_loc, self.pacman_dir, width, height);
+        if self.board[[ahead.1, ahead.0]] != 1 {
+            ghost_ai::advance(
+                &mut self.pacman_loc,
+                self.pacman_dir,
+                PACMAN_SPEED_TILES_PER_SEC,
+                dt,
+                width,
+                height,
+            );
+        }
+
+        let tile = (
+            ghost_ai::fine_to_tile(self.pacman_loc.0),
+            ghost_ai::fine_to_tile(self.pacman_loc.1),
+        );
+        if self.is_warp_cell(tile) {
+            if !self.pacman_just_warped {
+                if let Some(dest) = self.warp_destination(tile) {
+                    self.pacman_loc = (ghost_ai::tile_center(dest.0), ghost_ai::tile_center(dest.1));
+                    self.pacman_just_warped = true;
+                }
+            }
+        } else {
+            self.pacman_just_warped = false;
+        }
+    }
+
+    /// Resolves a warp for one ghost, mirroring `step_pacman`'s handling.
+    fn step_ghost_warp(&mut self, ghost: Ghost) {
+        let tile = {
+            let (loc, _, _, _) = self.ghost_state(ghost);
+            (ghost_ai::fine_to_tile(loc.0), ghost_ai::fine_to_tile(loc.1))
+        };
+
+        if self.is_warp_cell(tile) {
+            let already_warped = *self.ghost_state(ghost).3;
+            if !already_warped {
+                if let Some(dest) = self.warp_destination(tile) {
+                    let (loc, _, _, just_warped) = self.ghost_state(ghost);
+                    *loc = (ghost_ai::tile_center(dest.0), ghost_ai::tile_center(dest.1));
+                    *just_warped = true;
+                }
+            }
+        } else {
+            *self.ghost_state(ghost).3 = false;
+        }
+    }
+
+    fn spawn_fruit(&mut self) {
+        if self.fruit_active {
+            // per the source notes, a new fruit never appears while one's still out
+            return;
+        }
+        let (gx, gy) = self.ghost_spawn;
+        self.fruit_active = true;
+        self.fruit_timer = FRUIT_LIFETIME_SECS;
+        self.fruit_value = fruit_value_for_level(self.level);
+        self.fruit_loc = (gx + 3, gy + 5);
+    }
+
+    fn maybe_spawn_fruit(&mut self) {
+        if !self.fruit_spawned_70 && self.dots_eaten >= 70 {
+            self.fruit_spawned_70 = true;
+            self.spawn_fruit();
+        } else if !self.fruit_spawned_170 && self.dots_eaten >= 170 {
+            self.fruit_spawned_170 = true;
+            self.spawn_fruit();
+        }
+    }
+
+    /// Advances the whole simulation by `dt` seconds given Pac-Man's desired
+    /// direction, and reports whatever happened along the way.
+    pub fn step(&mut self, desired_dir: Direction, dt: f64) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+
+        self.step_pacman(desired_dir, dt);
+
+        let pac_tile = (
+            ghost_ai::fine_to_tile(self.pacman_loc.0),
+            ghost_ai::fine_to_tile(self.pacman_loc.1),
+        );
+        match self.board[[pac_tile.1, pac_tile.0]] {
+            2 => {
+                self.board[[pac_tile.1, pac_tile.0]] = 0;
+                self.points += 10;
+                self.dots_eaten += 1;
+                events.push(GameEvent::DotEaten);
+                self.maybe_spawn_fruit();
+            }
+            3 => {
+                self.board[[pac_tile.1, pac_tile.0]] = 0;
+                self.points += 50;
+                self.dots_eaten += 1;
+                self.frightened_timer = FRIGHTENED_DURATION_SECS;
+                self.ghost_eat_streak = 0;
+                for ghost in [Ghost::Blinky, Ghost::Pinky, Ghost::Inky, Ghost::Clyde] {
+                    *self.ghost_state(ghost).2 = GhostMode::Frightened;
+                }
+                events.push(GameEvent::DotEaten);
+                self.maybe_spawn_fruit();
+            }
+            _ => {}
+        }
+
+        if self.frightened_timer > 0.0 {
+            self.frightened_timer = (self.frightened_timer - dt).max(0.0);
+        }
+
+        if self.fruit_active {
+            self.fruit_timer -= dt;
+            if pac_tile == self.fruit_loc {
+                self.points += self.fruit_value;
+                self.fruit_active = false;
+            } else if self.fruit_timer <= 0.0 {
+                self.fruit_active = false;
+            }
+        }
+
+        self.step_ghosts(dt);
+
+        for ghost in [Ghost::Blinky, Ghost::Pinky, Ghost::Inky, Ghost::Clyde] {
+            let (loc, _, mode, _) = self.ghost_state(ghost);
+            let ghost_tile = (ghost_ai::fine_to_tile(loc.0), ghost_ai::fine_to_tile(loc.1));
+            if ghost_tile != pac_tile {
+                continue;
+            }
+
+            if *mode == GhostMode::Frightened {
+                self.ghost_eat_streak += 1;
+                let idx = (self.ghost_eat_streak - 1).min(GHOST_EATEN_POINTS.len() - 1);
+                self.points += GHOST_EATEN_POINTS[idx];
+                events.push(GameEvent::GhostEaten);
+                let home = self.ghost_home_loc(ghost);
+                let (loc, dir, mode, just_warped) = self.ghost_state(ghost);
+                *loc = home;
+                *dir = Direction::Up;
+                *mode = GhostMode::Scatter;
+                *just_warped = false;
+            } else {
+                self.lives = self.lives.saturating_sub(1);
+                self.reset_positions();
+                events.push(GameEvent::PacDied);
+                break;
+            }
+        }
+
+        if !self.level_cleared && self.dots_eaten >= self.dots_total {
+            self.level_cleared = true;
+            events.push(GameEvent::LevelCleared);
+        }
+
+        events
     }
 }
@@ -0,0 +1,56 @@
+use std::collections::{HashSet, VecDeque};
+
+use ndarray::Array2;
+
+use crate::pacman::WarpTable;
+
+/// Flood-fills from `start` across every non-wall tile, following warp pairs
+/// as extra edges (standing on one side of a warp also reaches its partner).
+/// Returns the full set of reachable tiles so callers can validate a level
+/// or just query connectivity.
+pub fn reachable_tiles(
+    board: &Array2<i32>,
+    start: (usize, usize),
+    warps: &WarpTable,
+) -> HashSet<(usize, usize)> {
+    let (height, width) = board.dim();
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    seen.insert(start);
+    queue.push_back(start);
+
+    while let Some(tile) = queue.pop_front() {
+        let (x, y) = tile;
+        let mut neighbors = Vec::with_capacity(5);
+        if y > 0 {
+            neighbors.push((x, y - 1));
+        }
+        if x > 0 {
+            neighbors.push((x - 1, y));
+        }
+        if y + 1 < height {
+            neighbors.push((x, y + 1));
+        }
+        if x + 1 < width {
+            neighbors.push((x + 1, y));
+        }
+
+        let value = board[[y, x]];
+        if value < 0 {
+            if let Some(&(a, b)) = warps.get(&value) {
+                neighbors.push(if a == tile { b } else { a });
+            }
+        }
+
+        for n in neighbors {
+            if board[[n.1, n.0]] == 1 {
+                continue;
+            }
+            if seen.insert(n) {
+                queue.push_back(n);
+            }
+        }
+    }
+
+    seen
+}
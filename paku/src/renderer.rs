@@ -0,0 +1,150 @@
+use std::io::{self, Write};
+
+use crate::ghost_ai::{self, Ghost, GhostMode};
+use crate::pacman::Game;
+
+/// Foreground colors used by the board and its entities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Default,
+    White,
+    Blue,
+    Cyan,
+    Red,
+    Pink,
+    Orange,
+    Yellow,
+    Green,
+}
+
+impl Color {
+    fn ansi_code(self) -> &'static str {
+        match self {
+            Color::Default => "39",
+            Color::White => "97",
+            Color::Blue => "34",
+            Color::Cyan => "36",
+            Color::Red => "31",
+            Color::Pink => "95",
+            Color::Orange => "33",
+            Color::Yellow => "93",
+            Color::Green => "32",
+        }
+    }
+}
+
+/// Writes ANSI escapes to a string, only emitting a color change when the
+/// color actually changes, and resetting at the end of every row.
+struct Emitter {
+    out: String,
+    current: Option<Color>,
+}
+
+impl Emitter {
+    fn new() -> Self {
+        Emitter {
+            out: String::new(),
+            current: None,
+        }
+    }
+
+    fn push(&mut self, ch: char, color: Color) {
+        if self.current != Some(color) {
+            self.out.push_str("\x1b[");
+            self.out.push_str(color.ansi_code());
+            self.out.push('m');
+            self.current = Some(color);
+        }
+        self.out.push(ch);
+    }
+
+    fn end_row(&mut self) {
+        self.out.push_str("\x1b[0m");
+        self.current = None;
+        self.out.push('\n');
+    }
+
+    fn finish(self) -> String {
+        self.out
+    }
+}
+
+fn board_glyph(value: i32) -> (char, Color) {
+    match value {
+        0 => (' ', Color::Default),
+        1 => ('#', Color::Blue),
+        2 => ('.', Color::White),
+        3 => ('o', Color::White),
+        v if v < 0 => ('~', Color::Cyan),
+        _ => ('?', Color::Default),
+    }
+}
+
+fn ghost_glyph(ghost: Ghost, mode: GhostMode) -> (char, Color) {
+    if mode == GhostMode::Frightened {
+        return ('M', Color::Blue);
+    }
+    match ghost {
+        Ghost::Blinky => ('M', Color::Red),
+        Ghost::Pinky => ('M', Color::Pink),
+        Ghost::Inky => ('M', Color::Cyan),
+        Ghost::Clyde => ('M', Color::Orange),
+    }
+}
+
+fn tile_of(loc: (f64, f64)) -> (usize, usize) {
+    (ghost_ai::fine_to_tile(loc.0), ghost_ai::fine_to_tile(loc.1))
+}
+
+/// Renders the board plus Pac-Man, the four ghosts, and fruit to a string of
+/// ANSI-colored glyphs, one line per row.
+pub fn render_to_string(game: &Game) -> String {
+    let (height, width) = game.board.dim();
+    let mut cells: Vec<Vec<(char, Color)>> = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| board_glyph(game.board[[y, x]]))
+                .collect()
+        })
+        .collect();
+
+    if game.fruit_active {
+        let (fx, fy) = game.fruit_loc;
+        if fy < height && fx < width {
+            cells[fy][fx] = ('*', Color::Green);
+        }
+    }
+
+    for (ghost, loc, mode) in [
+        (Ghost::Blinky, game.blinky_loc, game.blinky_mode),
+        (Ghost::Pinky, game.pinky_loc, game.pinky_mode),
+        (Ghost::Inky, game.inky_loc, game.inky_mode),
+        (Ghost::Clyde, game.clyde_loc, game.clyde_mode),
+    ] {
+        let (x, y) = tile_of(loc);
+        if y < height && x < width {
+            cells[y][x] = ghost_glyph(ghost, mode);
+        }
+    }
+
+    let (px, py) = tile_of(game.pacman_loc);
+    if py < height && px < width {
+        cells[py][px] = ('C', Color::Yellow);
+    }
+
+    let mut emitter = Emitter::new();
+    for row in cells {
+        for (ch, color) in row {
+            emitter.push(ch, color);
+        }
+        emitter.end_row();
+    }
+    emitter.finish()
+}
+
+/// Redraws the board in place by homing the cursor first, so repeated calls
+/// refresh the same screen region each tick instead of scrolling.
+pub fn draw_to_stdout(game: &Game) {
+    print!("\x1b[H{}", render_to_string(game));
+    let _ = io::stdout().flush();
+}